@@ -1,5 +1,7 @@
 #![crate_name = "doc"]
 
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
 pub enum FuncType {
     Abstract,
     Const,
@@ -20,491 +22,1688 @@ pub enum FuncType {
     Sqrt,
     Qbrt,
     Nthrt,
+    Comp,
+    Sinh,
+    Cosh,
+    Tanh,
+    Asinh,
+    Acosh,
+    Atanh,
+    Abs,
+}
+
+/// Classifies a real number as negative, zero, or positive, returning
+/// `-1`, `0`, or `1` respectively (unlike `f64::signum`, which has no zero).
+fn sign_of(v: f64) -> f64 {
+    if v > 0f64 {
+        1f64
+    } else if v < 0f64 {
+        -1f64
+    } else {
+        0f64
+    }
+}
+
+/// A scalar type a `FuncEval` tree can be evaluated over. Bundles the
+/// arithmetic and elementary operations every `Func*` node relies on, so the
+/// same expression tree can be evaluated over `f32`, `f64`, or `Complex`.
+pub trait Scalar:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// Lifts a literal (used by constant parameters such as `FuncConst::c`).
+    fn from_f64(v: f64) -> Self;
+
+    fn powf(self, n: f64) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn log(self, base: f64) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn atanh(self) -> Self;
+    fn abs(self) -> Self;
+    /// The sign of the value: `-1`, `0`, or `1` for the real scalars; for
+    /// `Complex`, the unit number in the value's direction (or `0`).
+    fn sign(self) -> Self;
+}
+
+impl Scalar for f64 {
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+
+    fn powf(self, n: f64) -> Self {
+        f64::powf(self, n)
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn log(self, base: f64) -> Self {
+        f64::log(self, base)
+    }
+
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        f64::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        f64::asin(self)
+    }
+
+    fn acos(self) -> Self {
+        f64::acos(self)
+    }
+
+    fn atan(self) -> Self {
+        f64::atan(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn cbrt(self) -> Self {
+        f64::cbrt(self)
+    }
+
+    fn sinh(self) -> Self {
+        f64::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        f64::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        f64::tanh(self)
+    }
+
+    fn asinh(self) -> Self {
+        f64::asinh(self)
+    }
+
+    fn acosh(self) -> Self {
+        f64::acosh(self)
+    }
+
+    fn atanh(self) -> Self {
+        f64::atanh(self)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn sign(self) -> Self {
+        sign_of(self)
+    }
+}
+
+impl Scalar for f32 {
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+
+    fn powf(self, n: f64) -> Self {
+        f32::powf(self, n as f32)
+    }
+
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        f32::ln(self)
+    }
+
+    fn log(self, base: f64) -> Self {
+        f32::log(self, base as f32)
+    }
+
+    fn sin(self) -> Self {
+        f32::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        f32::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        f32::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        f32::asin(self)
+    }
+
+    fn acos(self) -> Self {
+        f32::acos(self)
+    }
+
+    fn atan(self) -> Self {
+        f32::atan(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn cbrt(self) -> Self {
+        f32::cbrt(self)
+    }
+
+    fn sinh(self) -> Self {
+        f32::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        f32::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        f32::tanh(self)
+    }
+
+    fn asinh(self) -> Self {
+        f32::asinh(self)
+    }
+
+    fn acosh(self) -> Self {
+        f32::acosh(self)
+    }
+
+    fn atanh(self) -> Self {
+        f32::atanh(self)
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn sign(self) -> Self {
+        sign_of(self as f64) as f32
+    }
+}
+
+/// A complex number `re + im * i`, implementing `Scalar` so `FuncEval` trees
+/// can be evaluated over the complex plane (domain coloring, root finding,
+/// residues, ...).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// The modulus `|z|`.
+    pub fn modulus(self) -> f64 {
+        (self.re * self.re + self.im * self.im).sqrt()
+    }
+
+    /// The argument (angle) of `z`, in `(-pi, pi]`.
+    pub fn argument(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
 }
 
-/// Represents a function f that can be evaluated at x value
-pub trait FuncEval {
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: Complex) -> Complex {
+        let d = rhs.re * rhs.re + rhs.im * rhs.im;
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / d,
+            (self.im * rhs.re - self.re * rhs.im) / d,
+        )
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl Scalar for Complex {
+    fn from_f64(v: f64) -> Self {
+        Complex::new(v, 0f64)
+    }
+
+    fn powf(self, n: f64) -> Self {
+        (self.ln() * Complex::from_f64(n)).exp()
+    }
+
+    fn exp(self) -> Self {
+        let r = self.re.exp();
+        Complex::new(r * self.im.cos(), r * self.im.sin())
+    }
+
+    fn ln(self) -> Self {
+        Complex::new(self.modulus().ln(), self.argument())
+    }
+
+    fn log(self, base: f64) -> Self {
+        self.ln() / Complex::from_f64(base.ln())
+    }
+
+    fn sin(self) -> Self {
+        let iz = Complex::new(-self.im, self.re);
+        let e_pos = iz.exp();
+        let e_neg = (-iz).exp();
+        (e_pos - e_neg) / Complex::new(0f64, 2f64)
+    }
+
+    fn cos(self) -> Self {
+        let iz = Complex::new(-self.im, self.re);
+        let e_pos = iz.exp();
+        let e_neg = (-iz).exp();
+        (e_pos + e_neg) / Complex::from_f64(2f64)
+    }
+
+    fn tan(self) -> Self {
+        self.sin() / self.cos()
+    }
+
+    fn asin(self) -> Self {
+        let i = Complex::new(0f64, 1f64);
+        let root = (Complex::from_f64(1f64) - self * self).powf(0.5);
+        -i * (i * self + root).ln()
+    }
+
+    fn acos(self) -> Self {
+        Complex::from_f64(std::f64::consts::FRAC_PI_2) - self.asin()
+    }
+
+    fn atan(self) -> Self {
+        let i = Complex::new(0f64, 1f64);
+        let half_i = Complex::new(0f64, 0.5f64);
+        half_i * ((Complex::from_f64(1f64) - i * self).ln() - (Complex::from_f64(1f64) + i * self).ln())
+    }
+
+    fn sqrt(self) -> Self {
+        self.powf(0.5)
+    }
+
+    fn cbrt(self) -> Self {
+        self.powf(1f64 / 3f64)
+    }
+
+    fn sinh(self) -> Self {
+        let e_pos = self.exp();
+        let e_neg = (-self).exp();
+        (e_pos - e_neg) / Complex::from_f64(2f64)
+    }
+
+    fn cosh(self) -> Self {
+        let e_pos = self.exp();
+        let e_neg = (-self).exp();
+        (e_pos + e_neg) / Complex::from_f64(2f64)
+    }
+
+    fn tanh(self) -> Self {
+        self.sinh() / self.cosh()
+    }
+
+    fn asinh(self) -> Self {
+        let root = (self * self + Complex::from_f64(1f64)).powf(0.5);
+        (self + root).ln()
+    }
+
+    fn acosh(self) -> Self {
+        let root = (self * self - Complex::from_f64(1f64)).powf(0.5);
+        (self + root).ln()
+    }
+
+    fn atanh(self) -> Self {
+        ((Complex::from_f64(1f64) + self).ln() - (Complex::from_f64(1f64) - self).ln()) / Complex::from_f64(2f64)
+    }
+
+    /// The modulus `|z|`, lifted back into `Complex` with a zero imaginary part.
+    fn abs(self) -> Self {
+        Complex::from_f64(self.modulus())
+    }
+
+    /// The unit complex number pointing in `z`'s direction, or `0` if `z` is `0`.
+    fn sign(self) -> Self {
+        let m = self.modulus();
+        if m == 0f64 {
+            Complex::from_f64(0f64)
+        } else {
+            self / Complex::from_f64(m)
+        }
+    }
+}
+
+/// Represents a function f that can be evaluated at an x value of scalar
+/// type `S` (`f32`, `f64`, `Complex`, ...).
+pub trait FuncEval<S: Scalar> {
     /// Evaluates a function f at x
-    fn eval(&self, x: f64) -> f64;
+    fn eval(&self, x: S) -> S;
+}
+
+pub struct Func {
+    f_type: FuncType,
+}
+
+impl Func {
+    pub fn new() -> Self {
+        Self {f_type: FuncType::Abstract}
+    }
+}
+
+impl<S: Scalar> FuncEval<S> for Func {
+    fn eval(&self, _: S) -> S {
+        S::from_f64(0f64)
+    }
+}
+
+/// Represents a constant function
+/// Given x a real number C(x) = c
+pub struct FuncConst {
+    c: f64,
+}
+
+impl FuncConst {
+    pub fn new(c: f64) -> Self {
+        Self { c }
+    }
+}
+
+impl<S: Scalar> FuncEval<S> for FuncConst {
+    fn eval(&self, _: S) -> S {
+        S::from_f64(self.c)
+    }
+}
+
+/// Represents an f identity function,
+/// given x a real number then f(x) = x
+pub struct FuncIdem {
+    f_type: FuncType,
+}
+
+impl FuncIdem {
+    pub fn new() -> Self {
+        Self {
+            f_type: FuncType::Idem,
+        }
+    }
+}
+
+impl<S: Scalar> FuncEval<S> for FuncIdem {
+    fn eval(&self, x: S) -> S {
+        x
+    }
+}
+
+/// Represents a sum function s of two functions f and g
+/// Given x a real number s(x) = f(x) + g(x)
+pub struct FuncSum<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FuncSum<F, G> {
+    pub fn new(f: F, g: G) -> Self {
+        Self { f, g }
+    }
+}
+
+impl<S: Scalar, F, G> FuncEval<S> for FuncSum<F, G>
+where
+    F: FuncEval<S>,
+    G: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x) + self.g.eval(x)
+    }
+}
+
+/// Represents a product function p of two functions f and g
+/// Given x a real number then p(x) = f(x) * g(x)
+pub struct FuncProd<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FuncProd<F, G> {
+    pub fn new(f: F, g: G) -> Self {
+        Self { f, g }
+    }
+}
+
+impl<S: Scalar, F, G> FuncEval<S> for FuncProd<F, G>
+where
+    F: FuncEval<S>,
+    G: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x) * self.g.eval(x)
+    }
+}
+
+/// Represents the difference of two functions f and g
+/// Given x a real number then (f - g)(x) = f(x) - g(x)
+pub struct FuncSub<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FuncSub<F, G> {
+    pub fn new(f: F, g: G) -> Self {
+        Self { f, g }
+    }
+}
+
+impl<S: Scalar, F, G> FuncEval<S> for FuncSub<F, G>
+where
+    F: FuncEval<S>,
+    G: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x) - self.g.eval(x)
+    }
+}
+
+/// Represents the quotient of two functions f and g
+/// Given x a real number then (f / g)(x) = f(x) / g(x)
+pub struct FuncDiv<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FuncDiv<F, G> {
+    pub fn new(f: F, g: G) -> Self {
+        Self { f, g }
+    }
+}
+
+impl<S: Scalar, F, G> FuncEval<S> for FuncDiv<F, G>
+where
+    F: FuncEval<S>,
+    G: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x) / self.g.eval(x)
+    }
+}
+
+/// Represents the composition of two functions f and g
+/// Given x a real number then (f . g)(x) = f(g(x))
+pub struct FuncComp<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> FuncComp<F, G> {
+    pub fn new(f: F, g: G) -> Self {
+        Self { f, g }
+    }
+}
+
+impl<S: Scalar, F, G> FuncEval<S> for FuncComp<F, G>
+where
+    F: FuncEval<S>,
+    G: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(self.g.eval(x))
+    }
+}
+
+/// Represents a power function p of a function f and constant n
+/// Given x a real number p(x) = f(x) ^ n
+pub struct FuncPow<T> {
+    f: T,
+    n: f64,
+}
+
+impl<T> FuncPow<T> {
+    pub fn new(f: T, n: f64) -> Self {
+        Self { f, n }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncPow<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).powf(self.n)
+    }
+}
+
+/// Represents a exponetial function exp of a constant a and a function f
+/// Given x a real number then exp(x) = a ^ f(x)
+pub struct FuncExpA<T> {
+    a: f64,
+    f: T,
+}
+
+impl<T> FuncExpA<T> {
+    pub fn new(a: f64, f: T) -> Self {
+        Self { a, f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncExpA<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        (self.f.eval(x) * S::from_f64(self.a.ln())).exp()
+    }
+}
+
+/// Represents an exponential function of Euler constant e and a fuction f
+/// Given x a real number exp(x) = e ^ f(x)
+pub struct FuncExpE<T> {
+    f: T,
+}
+
+impl<T> FuncExpE<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncExpE<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).exp()
+    }
+}
+
+/// Represents log base b function of f function
+/// Given x a positive real number then lg_b(x) = log_b(f(x))
+pub struct FuncLogA<T> {
+    b: f64,
+    f: T,
+}
+
+impl<T> FuncLogA<T> {
+    pub fn new(b: f64, f: T) -> Self {
+        Self { b, f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncLogA<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).log(self.b)
+    }
+}
+
+/// Represents neperian logarithm (natural log, log base e where e is Eulers constant) ln of function f
+/// Given x a positive real number ln(x) = log_e(f(x))
+pub struct FuncLogE<T> {
+    f: T,
+}
+
+impl<T> FuncLogE<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncLogE<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).ln()
+    }
+}
+
+/// Tags whether an angle is expressed in radians or degrees, so trigonometric
+/// nodes can make their angle convention explicit and checkable instead of
+/// silently assuming radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleUnit {
+    Radians,
+    Degrees,
+}
+
+/// A real-valued angle expressed in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radians(pub f64);
+
+/// A real-valued angle expressed in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Degrees(pub f64);
+
+impl Radians {
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+
+    /// Normalizes the angle into the canonical turn `(-pi, pi]`.
+    pub fn wrap(self) -> Radians {
+        let turn = std::f64::consts::TAU;
+        let mut r = self.0 % turn;
+        if r <= -std::f64::consts::PI {
+            r += turn;
+        } else if r > std::f64::consts::PI {
+            r -= turn;
+        }
+        Radians(r)
+    }
+}
+
+impl Degrees {
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+
+    /// Normalizes the angle into the canonical turn `(-180, 180]`.
+    pub fn wrap(self) -> Degrees {
+        let turn = 360f64;
+        let mut d = self.0 % turn;
+        if d <= -180f64 {
+            d += turn;
+        } else if d > 180f64 {
+            d -= turn;
+        }
+        Degrees(d)
+    }
+}
+
+/// Represents a sine function of a function f
+/// Given x a real number (an angle in `unit`) Sin(x) = sin(f(x))
+pub struct FuncSin<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncSin<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncSin<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let v = self.f.eval(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * S::from_f64(std::f64::consts::PI / 180f64),
+        };
+        radians.sin()
+    }
+}
+
+/// Represents a cosine function of a f function
+/// Given x a real number (an angle in `unit`) Cos(x) = cos(f(x))
+pub struct FuncCos<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncCos<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncCos<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let v = self.f.eval(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * S::from_f64(std::f64::consts::PI / 180f64),
+        };
+        radians.cos()
+    }
+}
+
+/// Represents a tangent function of a function f
+/// Given x a real number (an angle in `unit`, different from k*pi + pi/2 radians,
+/// i.e. k*180 + 90 degrees, where k is an integer)
+pub struct FuncTan<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncTan<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncTan<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let v = self.f.eval(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * S::from_f64(std::f64::consts::PI / 180f64),
+        };
+        radians.tan()
+    }
+}
+
+/// Represents a arc sine function of a f function
+/// Given x a real number (-1 <= x <= 1) then Asin(x) = asin(f(x)), the result
+/// tagged in the chosen output `unit`
+pub struct FuncAsin<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncAsin<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncAsin<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let radians = self.f.eval(x).asin();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * S::from_f64(180f64 / std::f64::consts::PI),
+        }
+    }
+}
+
+/// Represents a function arc cosine for a function f
+/// Given x a real number (-1 <= x <= 1) then Acos(x) = acos(f(x)), the result
+/// tagged in the chosen output `unit`
+pub struct FuncAcos<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncAcos<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncAcos<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let radians = self.f.eval(x).acos();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * S::from_f64(180f64 / std::f64::consts::PI),
+        }
+    }
+}
+
+/// Represents a arc tan function of a f function
+/// Given x a real number then Atan(x) = atan(f(x)), the result tagged in the
+/// chosen output `unit`
+pub struct FuncAtan<T> {
+    f: T,
+    unit: AngleUnit,
+}
+
+impl<T> FuncAtan<T> {
+    pub fn new(f: T) -> Self {
+        Self {
+            f,
+            unit: AngleUnit::Radians,
+        }
+    }
+
+    pub fn with_unit(f: T, unit: AngleUnit) -> Self {
+        Self { f, unit }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncAtan<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        let radians = self.f.eval(x).atan();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * S::from_f64(180f64 / std::f64::consts::PI),
+        }
+    }
+}
+
+/// Represents a square root function of a f function
+/// Given x a real number then Sqrt(x) = sqrt(f(x))
+pub struct FuncSqrt<T> {
+    f: T,
+}
+
+impl<T> FuncSqrt<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncSqrt<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).sqrt()
+    }
+}
+
+/// Represents a cubic root of a function f
+/// Given x a real number Qbrt(x) = qbrt(f(x))
+pub struct FuncQbrt<T> {
+    f: T,
+}
+
+impl<T> FuncQbrt<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
+    }
+}
+
+impl<S: Scalar, T> FuncEval<S> for FuncQbrt<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).cbrt()
+    }
 }
 
-pub struct Func {
-    f_type: FuncType,
+/// Represents a nth root of a function f
+/// Given x a real number and n != 0 then Nroot(x) = (f(x))^(1/n)
+pub struct FuncNthrt<T> {
+    n: f64,
+    f: T,
 }
 
-impl Func {
-    pub fn new() -> Self {
-        Self {f_type: FuncType::Abstract}
+impl<T> FuncNthrt<T> {
+    pub fn new(n: f64, f: T) -> Self {
+        assert!(n != 0f64);
+        Self { n, f }
     }
 }
 
-impl FuncEval for Func {
-    fn eval(&self, _: f64) -> f64 {
-        0f64
+impl<S: Scalar, T> FuncEval<S> for FuncNthrt<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).powf(1f64 / self.n)
     }
 }
 
-/// Represents a constant function
-/// Given x a real number C(x) = c
-pub struct FuncConst {
-    c: f64,
+/// Represents a hyperbolic sine function of a function f
+/// Given x a real number Sinh(x) = sinh(f(x))
+pub struct FuncSinh<T> {
+    f: T,
 }
 
-impl FuncConst {
-    pub fn new(c: f64) -> Self {
-        Self { c }
+impl<T> FuncSinh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl FuncEval for FuncConst {
-    fn eval(&self, x: f64) -> f64 {
-        self.c
+impl<S: Scalar, T> FuncEval<S> for FuncSinh<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).sinh()
     }
 }
 
-/// Represents an f identity function,
-/// given x a real number then f(x) = x
-pub struct FuncIdem {
-    f_type: FuncType,
+/// Represents a hyperbolic cosine function of a function f
+/// Given x a real number Cosh(x) = cosh(f(x))
+pub struct FuncCosh<T> {
+    f: T,
 }
 
-impl FuncIdem {
-    pub fn new() -> Self {
-        Self {
-            f_type: FuncType::Idem,
-        }
+impl<T> FuncCosh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl FuncEval for FuncIdem {
-    fn eval(&self, x: f64) -> f64 {
-        x
+impl<S: Scalar, T> FuncEval<S> for FuncCosh<T>
+where
+    T: FuncEval<S>,
+{
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).cosh()
     }
 }
 
-/// Represents a sum function s of two function f and g
-/// Given x a real number s(x) = f(x) + g(x)
-pub struct FuncSum<T>
-where
-    T: FuncEval,
-{
+/// Represents a hyperbolic tangent function of a function f
+/// Given x a real number Tanh(x) = tanh(f(x))
+pub struct FuncTanh<T> {
     f: T,
-    g: T,
 }
 
-impl<T> FuncSum<T>
-where
-    T: FuncEval,
-{
-    pub fn new(f: T, g: T) -> Self {
-        Self { f, g }
+impl<T> FuncTanh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl<T> FuncEval for FuncSum<T>
+impl<S: Scalar, T> FuncEval<S> for FuncTanh<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x) + self.g.eval(x)
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).tanh()
     }
 }
 
-/// Represents a product function p of two functions f and g
-/// Given x a real number then p(x) = f(x) * g(x)
-pub struct FuncProd<T>
-where
-    T: FuncEval,
-{
+/// Represents an inverse hyperbolic sine function of a function f
+/// Given x a real number Asinh(x) = asinh(f(x))
+pub struct FuncAsinh<T> {
     f: T,
-    g: T,
 }
 
-impl<T> FuncProd<T>
-where
-    T: FuncEval,
-{
-    pub fn new(f: T, g: T) -> Self {
-        Self { f, g }
+impl<T> FuncAsinh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl<T> FuncEval for FuncProd<T>
+impl<S: Scalar, T> FuncEval<S> for FuncAsinh<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x) * self.g.eval(x)
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).asinh()
     }
 }
 
-/// Represents a power function p of a function f and constant n
-/// Given x a real number p(x) = f(x) ^ n
-pub struct FuncPow<T>
-where
-    T: FuncEval,
-{
+/// Represents an inverse hyperbolic cosine function of a function f
+/// Given x a real number (x >= 1) Acosh(x) = acosh(f(x))
+pub struct FuncAcosh<T> {
     f: T,
-    n: f64,
 }
 
-impl<T> FuncPow<T>
-where
-    T: FuncEval,
-{
-    pub fn new(f: T, n: f64) -> Self {
-        Self { f, n }
+impl<T> FuncAcosh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl<T> FuncEval for FuncPow<T>
+impl<S: Scalar, T> FuncEval<S> for FuncAcosh<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).powf(self.n)
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).acosh()
     }
 }
 
-/// Represents a exponetial function exp of a constant a and a function f
-/// Given x a real number then exp(x) = a ^ f(x)
-pub struct FuncExpA<T>
-where
-    T: FuncEval,
-{
-    a: f64,
+/// Represents an inverse hyperbolic tangent function of a function f
+/// Given x a real number (-1 < x < 1) Atanh(x) = atanh(f(x))
+pub struct FuncAtanh<T> {
     f: T,
 }
 
-impl<T> FuncExpA<T>
-where
-    T: FuncEval,
-{
-    pub fn new(a: f64, f: T) -> Self {
-        Self { a, f }
+impl<T> FuncAtanh<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl<T> FuncEval for FuncExpA<T>
+impl<S: Scalar, T> FuncEval<S> for FuncAtanh<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.a.powf(self.f.eval(x))
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).atanh()
     }
 }
 
-/// Represents an exponential function of Euler constant e and a fuction f
-/// Given x a real number exp(x) = e ^ f(x)
-pub struct FuncExpE<T>
-where
-    T: FuncEval,
-{
+/// Represents the absolute value of a function f
+/// Given x a real number Abs(x) = |f(x)|
+pub struct FuncAbs<T> {
     f: T,
 }
 
-impl<T> FuncExpE<T>
-where
-    T: FuncEval,
-{
+impl<T> FuncAbs<T> {
     pub fn new(f: T) -> Self {
         Self { f }
     }
 }
 
-impl<T> FuncEval for FuncExpE<T>
+impl<S: Scalar, T> FuncEval<S> for FuncAbs<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        std::f64::consts::E.powf(x)
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).abs()
     }
 }
 
-/// Represents log base b function of f function
-/// Given x a positive real number then lg_b(x) = log_b(f(x))
-pub struct FuncLogA<T>
-where
-    T: FuncEval,
-{
-    b: f64,
+/// Represents the sign of a function f
+/// Given x a real number Sign(x) = -1, 0, or 1 matching the sign of f(x)
+///
+/// Intentionally has no matching `FuncType::Sign` variant; only `FuncType::Abs`
+/// was added alongside it.
+pub struct FuncSign<T> {
     f: T,
 }
 
-impl<T> FuncLogA<T>
-where
-    T: FuncEval,
-{
-    pub fn new(b: f64, f: T) -> Self {
-        Self { b, f }
+impl<T> FuncSign<T> {
+    pub fn new(f: T) -> Self {
+        Self { f }
     }
 }
 
-impl<T> FuncEval for FuncLogA<T>
+impl<S: Scalar, T> FuncEval<S> for FuncSign<T>
 where
-    T: FuncEval,
+    T: FuncEval<S>,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).log(self.b)
+    fn eval(&self, x: S) -> S {
+        self.f.eval(x).sign()
     }
 }
 
-/// Represents neperian logarithm (natural log, log base e where e is Eulers constant) ln of function f
-/// Given x a positive real number ln(x) = log_e(f(x))
-pub struct FuncLogE<T>
-where
-    T: FuncEval,
-{
-    f: T,
+/// A dual number `re + eps * epsilon` (with `epsilon^2 = 0`) used to carry a
+/// value `re` alongside its derivative `eps` through an expression tree.
+///
+/// Evaluating a tree through `FuncDiff::eval_dual` on a `Dual` seeded with `eps = 1` propagates
+/// the derivative via the chain rule at every node, so the `eps` component of
+/// the result is the exact derivative of the tree at `re` (forward-mode
+/// automatic differentiation).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    pub re: f64,
+    pub eps: f64,
 }
 
-impl<T> FuncLogE<T>
-where
-    T: FuncEval,
-{
-    pub fn new(f: T) -> Self {
-        Self { f }
+impl Dual {
+    pub fn new(re: f64, eps: f64) -> Self {
+        Self { re, eps }
+    }
+
+    /// A dual number with zero derivative, for lifting plain constants.
+    pub fn constant(re: f64) -> Self {
+        Self { re, eps: 0f64 }
+    }
+
+    /// A dual number seeded as the differentiation variable (`eps = 1`).
+    pub fn variable(re: f64) -> Self {
+        Self { re, eps: 1f64 }
+    }
+
+    /// `x^n` with `n` a constant real exponent.
+    pub fn powf(self, n: f64) -> Self {
+        Self::new(self.re.powf(n), n * self.re.powf(n - 1f64) * self.eps)
+    }
+
+    /// `a^x` with `a` a constant real base.
+    pub fn expa(self, a: f64) -> Self {
+        let v = a.powf(self.re);
+        Self::new(v, v * a.ln() * self.eps)
+    }
+
+    pub fn ln(self) -> Self {
+        Self::new(self.re.ln(), self.eps / self.re)
+    }
+
+    pub fn log(self, b: f64) -> Self {
+        Self::new(self.re.log(b), self.eps / (self.re * b.ln()))
+    }
+
+    pub fn sin(self) -> Self {
+        Self::new(self.re.sin(), self.re.cos() * self.eps)
+    }
+
+    pub fn cos(self) -> Self {
+        Self::new(self.re.cos(), -self.re.sin() * self.eps)
+    }
+
+    pub fn tan(self) -> Self {
+        let c = self.re.cos();
+        Self::new(self.re.tan(), self.eps / (c * c))
+    }
+
+    pub fn asin(self) -> Self {
+        Self::new(self.re.asin(), self.eps / (1f64 - self.re * self.re).sqrt())
+    }
+
+    pub fn acos(self) -> Self {
+        Self::new(self.re.acos(), -self.eps / (1f64 - self.re * self.re).sqrt())
+    }
+
+    pub fn atan(self) -> Self {
+        Self::new(self.re.atan(), self.eps / (1f64 + self.re * self.re))
+    }
+
+    pub fn sqrt(self) -> Self {
+        let s = self.re.sqrt();
+        Self::new(s, self.eps / (2f64 * s))
+    }
+
+    pub fn cbrt(self) -> Self {
+        let c = self.re.cbrt();
+        Self::new(c, self.eps / (3f64 * c * c))
+    }
+
+    pub fn sinh(self) -> Self {
+        Self::new(self.re.sinh(), self.re.cosh() * self.eps)
+    }
+
+    pub fn cosh(self) -> Self {
+        Self::new(self.re.cosh(), self.re.sinh() * self.eps)
+    }
+
+    pub fn tanh(self) -> Self {
+        let t = self.re.tanh();
+        Self::new(t, (1f64 - t * t) * self.eps)
+    }
+
+    pub fn asinh(self) -> Self {
+        Self::new(self.re.asinh(), self.eps / (self.re * self.re + 1f64).sqrt())
+    }
+
+    pub fn acosh(self) -> Self {
+        Self::new(self.re.acosh(), self.eps / (self.re * self.re - 1f64).sqrt())
+    }
+
+    pub fn atanh(self) -> Self {
+        Self::new(self.re.atanh(), self.eps / (1f64 - self.re * self.re))
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.re.abs(), sign_of(self.re) * self.eps)
+    }
+
+    pub fn sign(self) -> Self {
+        Self::new(sign_of(self.re), 0f64)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self.re + rhs.re, self.eps + rhs.eps)
     }
 }
 
-impl<T> FuncEval for FuncLogE<T>
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual::new(self.re * rhs.re, self.eps * rhs.re + self.re * rhs.eps)
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, rhs: Dual) -> Dual {
+        Dual::new(
+            self.re / rhs.re,
+            (self.eps * rhs.re - self.re * rhs.eps) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+/// Represents a function f that can be evaluated on dual numbers, which
+/// propagates derivatives through the expression tree via the chain rule.
+pub trait FuncDiff {
+    /// Evaluates the function at a dual number, carrying its derivative.
+    fn eval_dual(&self, x: Dual) -> Dual;
+
+    /// Computes the exact derivative of the function at `x`, by seeding `x`
+    /// as the differentiation variable and reading back the `eps` component.
+    fn derivative(&self, x: f64) -> f64 {
+        self.eval_dual(Dual::variable(x)).eps
+    }
+}
+
+impl FuncDiff for Func {
+    fn eval_dual(&self, _: Dual) -> Dual {
+        Dual::constant(0f64)
+    }
+}
+
+impl FuncDiff for FuncConst {
+    fn eval_dual(&self, _: Dual) -> Dual {
+        Dual::constant(self.c)
+    }
+}
+
+impl FuncDiff for FuncIdem {
+    fn eval_dual(&self, x: Dual) -> Dual {
+        x
+    }
+}
+
+impl<F, G> FuncDiff for FuncSum<F, G>
 where
-    T: FuncEval,
+    F: FuncDiff,
+    G: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).ln()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x) + self.g.eval_dual(x)
     }
 }
 
-/// Represents a sine function of a function f
-/// Given x a real number (representing an angle in radians) Sin(x) = sin(f(x))
-pub struct FuncSin<T>
+impl<F, G> FuncDiff for FuncProd<F, G>
 where
-    T: FuncEval,
+    F: FuncDiff,
+    G: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x) * self.g.eval_dual(x)
+    }
 }
 
-impl<T> FuncSin<T>
+impl<F, G> FuncDiff for FuncSub<F, G>
 where
-    T: FuncEval,
+    F: FuncDiff,
+    G: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x) - self.g.eval_dual(x)
     }
 }
 
-impl<T> FuncEval for FuncSin<T>
+impl<F, G> FuncDiff for FuncDiv<F, G>
 where
-    T: FuncEval,
+    F: FuncDiff,
+    G: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).sin()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x) / self.g.eval_dual(x)
     }
 }
 
-/// Represents a cosine function of a f function
-/// Given x a real number (representing an angle in radians) Cos(x) = cos(f(x))
-pub struct FuncCos<T>
+impl<F, G> FuncDiff for FuncComp<F, G>
 where
-    T: FuncEval,
+    F: FuncDiff,
+    G: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(self.g.eval_dual(x))
+    }
 }
 
-impl<T> FuncCos<T>
+impl<T> FuncDiff for FuncPow<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).powf(self.n)
     }
 }
 
-impl<T> FuncEval for FuncCos<T>
+impl<T> FuncDiff for FuncExpA<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).cos()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).expa(self.a)
     }
 }
 
-/// Represents a tangent function of a function f
-/// Given x a real number (different from k*pi + pi/2 where k is an integer)
-pub struct FuncTan<T>
+impl<T> FuncDiff for FuncExpE<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).expa(std::f64::consts::E)
+    }
 }
 
-impl<T> FuncTan<T>
+impl<T> FuncDiff for FuncLogA<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).log(self.b)
     }
 }
 
-impl<T> FuncEval for FuncTan<T>
+impl<T> FuncDiff for FuncLogE<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).tan()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).ln()
     }
 }
 
-/// Represents a arc sine function of a f function
-/// Given x a real number (-1 <= x <= 1) then Asin(x) = asin(f(x))
-pub struct FuncAsin<T>
+impl<T> FuncDiff for FuncSin<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let v = self.f.eval_dual(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * Dual::constant(std::f64::consts::PI / 180f64),
+        };
+        radians.sin()
+    }
 }
 
-impl<T> FuncAsin<T>
+impl<T> FuncDiff for FuncCos<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let v = self.f.eval_dual(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * Dual::constant(std::f64::consts::PI / 180f64),
+        };
+        radians.cos()
     }
 }
 
-impl<T> FuncEval for FuncAsin<T>
+impl<T> FuncDiff for FuncTan<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).asin()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let v = self.f.eval_dual(x);
+        let radians = match self.unit {
+            AngleUnit::Radians => v,
+            AngleUnit::Degrees => v * Dual::constant(std::f64::consts::PI / 180f64),
+        };
+        radians.tan()
     }
 }
 
-/// Represents a function arc cosine for a function f
-/// Given x a real number (-1 <= x <= 1) then Acos(x) = acos(f(x))
-pub struct FuncAcos<T>
+impl<T> FuncDiff for FuncAsin<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let radians = self.f.eval_dual(x).asin();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * Dual::constant(180f64 / std::f64::consts::PI),
+        }
+    }
 }
 
-impl<T> FuncAcos<T>
+impl<T> FuncDiff for FuncAcos<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let radians = self.f.eval_dual(x).acos();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * Dual::constant(180f64 / std::f64::consts::PI),
+        }
     }
 }
 
-/// Represents a arc tan function of a f function
-/// Given x a real number then Atan(x) = atan(f(x))
-pub struct FuncAtan<T>
+impl<T> FuncDiff for FuncAtan<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        let radians = self.f.eval_dual(x).atan();
+        match self.unit {
+            AngleUnit::Radians => radians,
+            AngleUnit::Degrees => radians * Dual::constant(180f64 / std::f64::consts::PI),
+        }
+    }
 }
 
-impl<T> FuncAtan<T>
+impl<T> FuncDiff for FuncSqrt<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).sqrt()
     }
 }
 
-impl<T> FuncEval for FuncAtan<T>
+impl<T> FuncDiff for FuncQbrt<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).atan()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).cbrt()
     }
 }
 
-/// Represents a square root function of a f function
-/// Given x a real number then Sqrt(x) = sqrt(f(x))
-pub struct FuncSqrt<T> {
-    f: T,
+impl<T> FuncDiff for FuncNthrt<T>
+where
+    T: FuncDiff,
+{
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).powf(1f64 / self.n)
+    }
 }
 
-impl<T> FuncSqrt<T>
+impl<T> FuncDiff for FuncSinh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).sinh()
     }
 }
 
-impl<T> FuncEval for FuncSqrt<T>
+impl<T> FuncDiff for FuncCosh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).sqrt()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).cosh()
     }
 }
 
-/// Represents a cubic root of a function f
-/// Given x a real number Qbrt(x) = qbrt(f(x))
-pub struct FuncQbrt<T>
+impl<T> FuncDiff for FuncTanh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).tanh()
+    }
 }
 
-impl<T> FuncQbrt<T>
+impl<T> FuncDiff for FuncAsinh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(f: T) -> Self {
-        Self { f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).asinh()
     }
 }
 
-impl<T> FuncEval for FuncQbrt<T>
+impl<T> FuncDiff for FuncAcosh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).cbrt()
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).acosh()
     }
 }
 
-/// Represents a nth root of a function f
-/// Given x a real number and n != 0 then Nroot(x) = (f(x))^(1/n)
-pub struct FuncNthrt<T>
+impl<T> FuncDiff for FuncAtanh<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    n: f64,
-    f: T,
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).atanh()
+    }
 }
 
-impl<T> FuncNthrt<T>
+impl<T> FuncDiff for FuncAbs<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    pub fn new(n: f64, f: T) -> Self {
-        assert!(n != 0f64);
-        Self { n, f }
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).abs()
     }
 }
 
-impl<T> FuncEval for FuncNthrt<T>
+impl<T> FuncDiff for FuncSign<T>
 where
-    T: FuncEval,
+    T: FuncDiff,
 {
-    fn eval(&self, x: f64) -> f64 {
-        self.f.eval(x).powf(1f64 / self.n)
+    fn eval_dual(&self, x: Dual) -> Dual {
+        self.f.eval_dual(x).sign()
+    }
+}
+
+/// Extension trait adding grid sampling to any real-valued `FuncEval`, so
+/// plotting and tabulation callers don't have to re-implement the loop and
+/// non-finite filtering themselves.
+///
+/// Deliberately not a default method directly on `FuncEval`: sampling only
+/// makes sense for real-valued (`f64`) output, so it is pinned here via the
+/// `FuncEval<f64>` supertrait bound instead of being generic over `Scalar`.
+/// The non-finite-skipping behavior is exposed as an explicit
+/// `skip_non_finite` parameter rather than a separate method.
+pub trait FuncSample: FuncEval<f64> {
+    /// Evaluates the function at `steps` evenly spaced points across
+    /// `[start, end]`. When `skip_non_finite` is set, points whose output is
+    /// not finite (e.g. `tan` poles or out-of-domain `sqrt`/`asin` values)
+    /// are omitted from the result.
+    fn sample(&self, start: f64, end: f64, steps: usize, skip_non_finite: bool) -> Vec<(f64, f64)> {
+        if steps == 0 {
+            return Vec::new();
+        }
+        let denom = if steps > 1 { (steps - 1) as f64 } else { 1f64 };
+        let mut points = Vec::with_capacity(steps);
+        for i in 0..steps {
+            let x = start + (end - start) * (i as f64) / denom;
+            let y = self.eval(x);
+            if skip_non_finite && !y.is_finite() {
+                continue;
+            }
+            points.push((x, y));
+        }
+        points
     }
 }
+
+impl<T: FuncEval<f64>> FuncSample for T {}